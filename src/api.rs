@@ -0,0 +1,25 @@
+//! `Web3` namespace API helpers.
+
+use std::fmt;
+
+/// Id of a pub-sub subscription.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubscriptionId(String);
+
+impl From<String> for SubscriptionId {
+    fn from(value: String) -> Self {
+        SubscriptionId(value)
+    }
+}
+
+impl From<&str> for SubscriptionId {
+    fn from(value: &str) -> Self {
+        SubscriptionId(value.to_owned())
+    }
+}
+
+impl fmt::Display for SubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,53 @@
+//! Web3 error.
+
+use crate::rpc::Error as RpcError;
+use std::io;
+
+/// Web3 `Result` type.
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+/// Errors which can occur when talking to a node.
+#[derive(Debug)]
+pub enum Error {
+    /// Server is unreachable.
+    Unreachable,
+    /// Decoding the response failed.
+    Decoder(String),
+    /// The response was not valid.
+    InvalidResponse(String),
+    /// A transport-level error occurred.
+    Transport(String),
+    /// The node returned a JSON-RPC error.
+    Rpc(RpcError),
+    /// An I/O error occurred.
+    Io(io::Error),
+    /// An internal invariant was violated.
+    Internal,
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Decoder(format!("{:?}", err))
+    }
+}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        use Error::*;
+        match (self, other) {
+            (Unreachable, Unreachable) | (Internal, Internal) => true,
+            (Decoder(a), Decoder(b))
+            | (InvalidResponse(a), InvalidResponse(b))
+            | (Transport(a), Transport(b)) => a == b,
+            (Rpc(a), Rpc(b)) => a == b,
+            (Io(a), Io(b)) => a.kind() == b.kind(),
+            _ => false,
+        }
+    }
+}
@@ -29,6 +29,87 @@ impl<T, F> CallFuture<T, F> {
             _marker: PhantomData,
         }
     }
+
+    /// Map the decoded value through `f`, yielding a lightweight wrapper future.
+    pub fn map<U, G: FnOnce(T) -> U>(self, f: G) -> MapCallFuture<T, F, G> {
+        MapCallFuture { inner: self, f: Some(f) }
+    }
+
+    /// Map the decoded value through a fallible `f`, flattening the result.
+    pub fn and_then<U, G: FnOnce(T) -> error::Result<U>>(self, f: G) -> AndThenCallFuture<T, F, G> {
+        AndThenCallFuture { inner: self, f: Some(f) }
+    }
+
+    /// Run `f` on a reference to the decoded value, yielding the value unchanged.
+    pub fn inspect<G: FnOnce(&T)>(self, f: G) -> InspectCallFuture<T, F, G> {
+        InspectCallFuture { inner: self, f: Some(f) }
+    }
+}
+
+/// Future returned by [`CallFuture::map`].
+#[derive(Debug)]
+pub struct MapCallFuture<T, F, G> {
+    inner: CallFuture<T, F>,
+    f: Option<G>,
+}
+
+impl<T, F, U, G> Future for MapCallFuture<T, F, G>
+where
+    CallFuture<T, F>: Future<Output = error::Result<T>> + Unpin,
+    G: FnOnce(T) -> U + Unpin,
+{
+    type Output = error::Result<U>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let value = ready!(self.inner.poll_unpin(ctx));
+        let f = self.f.take().expect("polled after completion");
+        Poll::Ready(value.map(f))
+    }
+}
+
+/// Future returned by [`CallFuture::and_then`].
+#[derive(Debug)]
+pub struct AndThenCallFuture<T, F, G> {
+    inner: CallFuture<T, F>,
+    f: Option<G>,
+}
+
+impl<T, F, U, G> Future for AndThenCallFuture<T, F, G>
+where
+    CallFuture<T, F>: Future<Output = error::Result<T>> + Unpin,
+    G: FnOnce(T) -> error::Result<U> + Unpin,
+{
+    type Output = error::Result<U>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let value = ready!(self.inner.poll_unpin(ctx));
+        let f = self.f.take().expect("polled after completion");
+        Poll::Ready(value.and_then(f))
+    }
+}
+
+/// Future returned by [`CallFuture::inspect`].
+#[derive(Debug)]
+pub struct InspectCallFuture<T, F, G> {
+    inner: CallFuture<T, F>,
+    f: Option<G>,
+}
+
+impl<T, F, G> Future for InspectCallFuture<T, F, G>
+where
+    CallFuture<T, F>: Future<Output = error::Result<T>> + Unpin,
+    G: FnOnce(&T) + Unpin,
+{
+    type Output = error::Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let value = ready!(self.inner.poll_unpin(ctx));
+        let f = self.f.take().expect("polled after completion");
+        if let Ok(ref value) = value {
+            f(value);
+        }
+        Poll::Ready(value)
+    }
 }
 
 impl<T, F> Future for CallFuture<T, F>
@@ -44,6 +125,43 @@ where
     }
 }
 
+/// Batch value-decoder future.
+/// Wraps a future yielding a batch of raw `rpc::Value` results and deserializes
+/// each successful element into `T`, preserving per-call errors.
+#[derive(Debug)]
+pub struct BatchCallFuture<T, F> {
+    inner: F,
+    _marker: PhantomData<T>,
+}
+
+impl<T, F> BatchCallFuture<T, F> {
+    /// Create a new BatchCallFuture wrapping the inner future.
+    pub fn new(inner: F) -> Self {
+        BatchCallFuture {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Future for BatchCallFuture<T, F>
+where
+    T: serde::de::DeserializeOwned + Unpin,
+    F: Future<Output = error::Result<Vec<error::Result<rpc::Value>>>> + Unpin,
+{
+    type Output = error::Result<Vec<error::Result<T>>>;
+
+    fn poll(mut self: Pin<&mut Self>, ctx: &mut Context) -> Poll<Self::Output> {
+        let values = ready!(self.inner.poll_unpin(ctx));
+        Poll::Ready(values.map(|values| {
+            values
+                .into_iter()
+                .map(|value| value.and_then(|value| serde_json::from_value(value).map_err(Into::into)))
+                .collect()
+        }))
+    }
+}
+
 /// Serialize a type. Panics if the type is returns error during serialization.
 pub fn serialize<T: serde::Serialize>(t: &T) -> rpc::Value {
     serde_json::to_value(t).expect("Types never fail to serialize.")
@@ -64,6 +182,16 @@ pub fn build_request(id: usize, method: &str, params: Vec<rpc::Value>) -> rpc::C
     })
 }
 
+/// Build a batch JSON-RPC request from a list of `(id, method, params)` tuples.
+pub fn build_batch_request(calls: Vec<(usize, &str, Vec<rpc::Value>)>) -> rpc::Request {
+    rpc::Request::Batch(
+        calls
+            .into_iter()
+            .map(|(id, method, params)| build_request(id, method, params))
+            .collect(),
+    )
+}
+
 /// Parse bytes slice into JSON-RPC response.
 pub fn to_response_from_slice(response: &[u8]) -> error::Result<rpc::Response> {
     serde_json::from_slice(response).map_err(|e| error::Error::InvalidResponse(format!("{:?}", e)))
@@ -87,6 +215,100 @@ pub fn to_result_from_output(output: rpc::Output) -> error::Result<rpc::Value> {
     }
 }
 
+/// Parse bytes slice into a batch of JSON-RPC `Result`s.
+///
+/// The outputs are re-ordered by their `id` so that the returned vector matches
+/// the order of the originating batch request, as servers are free to return
+/// the individual replies in any order. This assumes the numeric ids produced
+/// by [`build_request`]/[`build_batch_request`] (decimal strings are also
+/// accepted); ids that are neither are treated as equal and keep their received
+/// order via the stable sort.
+pub fn to_batch_results_from_slice(response: &[u8]) -> error::Result<Vec<error::Result<rpc::Value>>> {
+    let mut outputs: Vec<rpc::Output> =
+        serde_json::from_slice(response).map_err(|e| error::Error::InvalidResponse(format!("{:?}", e)))?;
+    outputs.sort_by_key(output_id);
+    to_results_from_outputs(outputs)
+}
+
+/// Numeric ordering key for an `rpc::Output`, used to restore batch ordering.
+fn output_id(output: &rpc::Output) -> u64 {
+    let id = match output {
+        rpc::Output::Success(success) => &success.id,
+        rpc::Output::Failure(failure) => &failure.id,
+    };
+    match id {
+        rpc::Id::Num(num) => *num,
+        rpc::Id::Str(s) => s.parse().unwrap_or(0),
+        rpc::Id::Null => 0,
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use crate::rpc;
+
+    #[test]
+    fn reorders_batch_outputs_by_id() {
+        // Server replied with the two outputs swapped relative to the request.
+        let response = br#"[
+            {"jsonrpc":"2.0","result":"0x2","id":2},
+            {"jsonrpc":"2.0","result":"0x1","id":1}
+        ]"#;
+
+        let results = super::to_batch_results_from_slice(response).unwrap();
+        let values: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(values, vec![rpc::Value::from("0x1"), rpc::Value::from("0x2")]);
+    }
+
+    #[test]
+    fn build_batch_request_produces_one_call_per_entry() {
+        let request = super::build_batch_request(vec![
+            (1, "eth_getBalance", vec![]),
+            (2, "eth_blockNumber", vec![]),
+        ]);
+        match request {
+            rpc::Request::Batch(calls) => assert_eq!(calls.len(), 2),
+            _ => panic!("expected a batch request"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod combinator_tests {
+    use super::CallFuture;
+    use crate::{error, rpc};
+    use futures::executor::block_on;
+    use futures::future;
+    use std::cell::Cell;
+
+    fn call(value: u64) -> CallFuture<u64, future::Ready<error::Result<rpc::Value>>> {
+        CallFuture::new(future::ready(Ok(rpc::Value::from(value))))
+    }
+
+    #[test]
+    fn map_transforms_the_decoded_value() {
+        let result = block_on(call(21).map(|x| x * 2));
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn and_then_flattens_the_result() {
+        let ok = block_on(call(1).and_then(|x| Ok(x + 1)));
+        assert_eq!(ok.unwrap(), 2);
+
+        let err = block_on(call(1).and_then(|_| Err::<u64, _>(error::Error::Unreachable)));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn inspect_observes_without_changing_the_value() {
+        let seen = Cell::new(0);
+        let result = block_on(call(7).inspect(|x| seen.set(*x)));
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(seen.get(), 7);
+    }
+}
+
 #[macro_use]
 #[cfg(test)]
 pub mod tests {
@@ -0,0 +1,61 @@
+//! Ethereum JSON-RPC client (and, via [`server`], a minimal service facade).
+
+use futures::{Future, Stream};
+
+/// RPC result type.
+pub use crate::error::Result;
+/// Re-export of the JSON-RPC types this crate speaks.
+pub use jsonrpc_core as rpc;
+
+pub mod api;
+pub mod error;
+pub mod helpers;
+pub mod server;
+pub mod transports;
+
+pub use crate::api::SubscriptionId;
+pub use crate::transports::{Ipc, PingConfig, Ws};
+
+/// Assigned request identifier.
+pub type RequestId = usize;
+
+/// Transport implementation.
+pub trait Transport: std::fmt::Debug + Clone {
+    /// The type of future this transport returns when a call is made.
+    type Out: Future<Output = error::Result<rpc::Value>>;
+
+    /// Prepare serializable RPC call for given method with parameters.
+    fn prepare(&self, method: &str, params: Vec<rpc::Value>) -> (RequestId, rpc::Call);
+
+    /// Execute prepared RPC call.
+    fn send(&self, id: RequestId, request: rpc::Call) -> Self::Out;
+
+    /// Execute remote method with given parameters.
+    fn execute(&self, method: &str, params: Vec<rpc::Value>) -> Self::Out {
+        let (id, request) = self.prepare(method, params);
+        self.send(id, request)
+    }
+}
+
+/// A transport implementation supporting batch requests.
+pub trait BatchTransport: Transport {
+    /// The type of future this transport returns when a batch is made.
+    type Batch: Future<Output = error::Result<Vec<error::Result<rpc::Value>>>>;
+
+    /// Execute a batch of prepared RPC calls.
+    fn send_batch<T>(&self, requests: T) -> Self::Batch
+    where
+        T: IntoIterator<Item = (RequestId, rpc::Call)>;
+}
+
+/// A transport implementation supporting pub sub subscriptions.
+pub trait DuplexTransport: Transport {
+    /// The type of stream this transport returns when a subscription is made.
+    type NotificationStream: Stream<Item = rpc::Value>;
+
+    /// Add a subscription to this transport.
+    fn subscribe(&self, id: SubscriptionId) -> error::Result<Self::NotificationStream>;
+
+    /// Remove a subscription from this transport.
+    fn unsubscribe(&self, id: SubscriptionId) -> error::Result<()>;
+}
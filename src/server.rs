@@ -0,0 +1,125 @@
+//! Transport-agnostic JSON-RPC server facade.
+//!
+//! This crate is otherwise client-only, but the same `rpc` types and `helpers`
+//! that build and decode requests are equally capable of *answering* them. The
+//! pieces here turn rust-web3 into something that can implement the protocol as
+//! well as call it — handy for mock nodes, test harnesses, and proxy middleware.
+
+use crate::error;
+use crate::rpc;
+
+/// A handler for a family of JSON-RPC methods.
+///
+/// A service inspects a single `rpc::Call` and either produces an
+/// `rpc::Output` for it or returns `None` to let another service try.
+pub trait Service {
+    /// Application-defined context threaded through every call.
+    type Data;
+
+    /// Handle a single call, returning `Ok(None)` if this service does not
+    /// recognise the method.
+    fn handle(&self, request: &rpc::Call, ctx: &Self::Data) -> error::Result<Option<rpc::Output>>;
+}
+
+/// A stack of services tried in order for each incoming call.
+pub struct Server<'a, D> {
+    services: Vec<&'a dyn Service<Data = D>>,
+}
+
+impl<'a, D> Default for Server<'a, D> {
+    fn default() -> Self {
+        Server { services: Vec::new() }
+    }
+}
+
+impl<'a, D> Server<'a, D> {
+    /// Create an empty server.
+    pub fn new() -> Self {
+        Server::default()
+    }
+
+    /// Register a service; services are consulted in registration order.
+    pub fn add_service(&mut self, service: &'a dyn Service<Data = D>) {
+        self.services.push(service);
+    }
+
+    /// Dispatch a single call to the first service that recognises it.
+    fn dispatch(&self, call: &rpc::Call, ctx: &D) -> error::Result<Option<rpc::Output>> {
+        for service in &self.services {
+            if let Some(output) = service.handle(call, ctx)? {
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Parse a byte slice into an `rpc::Request`, mapping a malformed payload to the
+/// appropriate JSON-RPC error object: `-32700` for invalid JSON, `-32600` for
+/// JSON that is not a valid request.
+pub fn from_slice(bytes: &[u8]) -> Result<rpc::Request, rpc::Error> {
+    let value: rpc::Value = serde_json::from_slice(bytes).map_err(|_| rpc::Error::new(rpc::ErrorCode::ParseError))?;
+    serde_json::from_value(value).map_err(|_| rpc::Error::new(rpc::ErrorCode::InvalidRequest))
+}
+
+/// Parse a string into an `rpc::Request`. See [`from_slice`].
+pub fn from_str(input: &str) -> Result<rpc::Request, rpc::Error> {
+    from_slice(input.as_bytes())
+}
+
+/// Answer a request, fanning a batch out across the registered services and
+/// reassembling the replies.
+///
+/// Notifications — calls carrying no `id` — produce no output, so a batch made
+/// up entirely of notifications yields `None`.
+pub fn serve<D>(server: &Server<D>, request: rpc::Request, ctx: &D) -> error::Result<Option<rpc::Response>> {
+    match request {
+        rpc::Request::Single(call) => Ok(respond(server, &call, ctx)?.map(rpc::Response::Single)),
+        rpc::Request::Batch(calls) => {
+            // Per JSON-RPC 2.0 an empty batch array is itself an invalid
+            // request and must be answered with a single error object.
+            if calls.is_empty() {
+                return Ok(Some(rpc::Response::Single(rpc::Output::Failure(rpc::Failure {
+                    jsonrpc: Some(rpc::Version::V2),
+                    error: rpc::Error::new(rpc::ErrorCode::InvalidRequest),
+                    id: rpc::Id::Null,
+                }))));
+            }
+            let mut outputs = Vec::new();
+            for call in &calls {
+                if let Some(output) = respond(server, call, ctx)? {
+                    outputs.push(output);
+                }
+            }
+            if outputs.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(rpc::Response::Batch(outputs)))
+            }
+        }
+    }
+}
+
+/// Produce the output for a single call, suppressing replies to notifications
+/// and synthesising a `-32601` "method not found" error for unknown methods.
+///
+/// A notification is still dispatched — a server must run its side effects —
+/// only its output is discarded.
+fn respond<D>(server: &Server<D>, call: &rpc::Call, ctx: &D) -> error::Result<Option<rpc::Output>> {
+    let handled = server.dispatch(call, ctx)?;
+
+    let id = match call {
+        rpc::Call::MethodCall(method) => method.id.clone(),
+        // Notifications and invalid entries are processed but get no response.
+        _ => return Ok(None),
+    };
+
+    match handled {
+        Some(output) => Ok(Some(output)),
+        None => Ok(Some(rpc::Output::Failure(rpc::Failure {
+            jsonrpc: Some(rpc::Version::V2),
+            error: rpc::Error::new(rpc::ErrorCode::MethodNotFound),
+            id,
+        }))),
+    }
+}
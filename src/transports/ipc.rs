@@ -0,0 +1,244 @@
+//! IPC transport.
+//!
+//! Talks to a local node over its IPC endpoint — a geth/parity `.ipc` Unix
+//! domain socket, or a Windows named pipe. Because it avoids the TCP/TLS stack
+//! it is the fastest path for co-located processes, and the only transport
+//! suitable for signing workflows that must never expose RPC over the network.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::api::SubscriptionId;
+use crate::error;
+use crate::helpers;
+use crate::rpc;
+use crate::{BatchTransport, DuplexTransport, RequestId, Transport};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, BoxFuture};
+use futures::stream::StreamExt;
+use futures::{AsyncReadExt, AsyncWriteExt, FutureExt};
+use parking_lot::Mutex;
+use tokio::net::UnixStream;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// Sender for a single pending request.
+type Pending = oneshot::Sender<error::Result<rpc::Value>>;
+/// Sender for an active subscription stream.
+type Subscription = mpsc::UnboundedSender<rpc::Value>;
+
+/// Shared state routing replies back to callers and notifications to streams.
+#[derive(Default)]
+struct Shared {
+    pending: Mutex<BTreeMap<RequestId, Pending>>,
+    subscriptions: Mutex<BTreeMap<SubscriptionId, Subscription>>,
+}
+
+impl Shared {
+    /// Dispatch a single decoded output to its waiting caller.
+    fn respond(&self, output: rpc::Output) {
+        let id = match &output {
+            rpc::Output::Success(s) => &s.id,
+            rpc::Output::Failure(f) => &f.id,
+        };
+        if let rpc::Id::Num(num) = id {
+            if let Some(pending) = self.pending.lock().remove(&(*num as usize)) {
+                let _ = pending.send(helpers::to_result_from_output(output));
+            }
+        }
+    }
+
+    /// Route a subscription notification to the registered stream, dropping it
+    /// if the stream has gone away.
+    fn notify(&self, notification: rpc::Notification) {
+        if let rpc::Params::Map(params) = notification.params {
+            let id = params.get("subscription").and_then(rpc::Value::as_str).map(SubscriptionId::from);
+            let result = params.get("result").cloned();
+            if let (Some(id), Some(result)) = (id, result) {
+                let mut subs = self.subscriptions.lock();
+                if let Some(stream) = subs.get(&id) {
+                    if stream.unbounded_send(result).is_err() {
+                        subs.remove(&id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// IPC transport over a Unix domain socket / named pipe.
+#[derive(Clone)]
+pub struct Ipc {
+    id: Arc<AtomicUsize>,
+    shared: Arc<Shared>,
+    messages: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Ipc {
+    /// Connect to the node at the given IPC path.
+    pub async fn new<P: AsRef<Path>>(path: P) -> error::Result<Self> {
+        let stream = UnixStream::connect(path).await?;
+        Ok(Self::with_stream(stream.compat()))
+    }
+
+    fn with_stream<S>(stream: S) -> Self
+    where
+        S: AsyncReadExt + AsyncWriteExt + Unpin + Send + 'static,
+    {
+        let shared = Arc::new(Shared::default());
+        let (messages, receiver) = mpsc::unbounded();
+        tokio::spawn(run(stream, shared.clone(), receiver));
+        Ipc {
+            id: Arc::new(AtomicUsize::new(1)),
+            shared,
+            messages,
+        }
+    }
+
+    fn send_request(&self, id: RequestId, request: rpc::Request) -> CallResult {
+        let (tx, rx) = oneshot::channel();
+        self.shared.pending.lock().insert(id, tx);
+
+        let mut payload = helpers::to_string(&request).into_bytes();
+        payload.push(b'\n');
+        if self.messages.unbounded_send(payload).is_err() {
+            self.shared.pending.lock().remove(&id);
+            return future::ready(Err(error::Error::Transport("IPC task terminated".into()))).boxed();
+        }
+
+        async move { rx.await.unwrap_or(Err(error::Error::Transport("IPC task terminated".into()))) }.boxed()
+    }
+}
+
+impl std::fmt::Debug for Ipc {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Ipc").finish()
+    }
+}
+
+/// Future produced by an IPC call.
+type CallResult = BoxFuture<'static, error::Result<rpc::Value>>;
+
+impl Transport for Ipc {
+    type Out = CallResult;
+
+    fn prepare(&self, method: &str, params: Vec<rpc::Value>) -> (RequestId, rpc::Call) {
+        let id = self.id.fetch_add(1, Ordering::AcqRel);
+        (id, helpers::build_request(id, method, params))
+    }
+
+    fn send(&self, id: RequestId, request: rpc::Call) -> Self::Out {
+        self.send_request(id, rpc::Request::Single(request))
+    }
+}
+
+impl BatchTransport for Ipc {
+    type Batch = BoxFuture<'static, error::Result<Vec<error::Result<rpc::Value>>>>;
+
+    fn send_batch<T>(&self, requests: T) -> Self::Batch
+    where
+        T: IntoIterator<Item = (RequestId, rpc::Call)>,
+    {
+        // Register one pending slot per call. The node answers with a single
+        // batch array that the read task splits back into individual outputs,
+        // each routed to its id's slot by `Shared::respond` — so replies that
+        // arrive reordered still land in the right place, and awaiting every
+        // slot restores the original request order regardless.
+        let mut calls = Vec::new();
+        let mut receivers = Vec::new();
+        {
+            let mut pending = self.shared.pending.lock();
+            for (id, call) in requests {
+                let (tx, rx) = oneshot::channel();
+                pending.insert(id, tx);
+                receivers.push(rx);
+                calls.push(call);
+            }
+        }
+
+        if calls.is_empty() {
+            return future::ready(Ok(Vec::new())).boxed();
+        }
+
+        let mut payload = helpers::to_string(&rpc::Request::Batch(calls)).into_bytes();
+        payload.push(b'\n');
+        if self.messages.unbounded_send(payload).is_err() {
+            return future::ready(Err(error::Error::Transport("IPC task terminated".into()))).boxed();
+        }
+
+        async move {
+            let mut results = Vec::with_capacity(receivers.len());
+            for rx in receivers {
+                results.push(rx.await.unwrap_or_else(|_| Err(error::Error::Transport("IPC task terminated".into()))));
+            }
+            Ok(results)
+        }
+        .boxed()
+    }
+}
+
+impl DuplexTransport for Ipc {
+    type NotificationStream = mpsc::UnboundedReceiver<rpc::Value>;
+
+    fn subscribe(&self, id: SubscriptionId) -> error::Result<Self::NotificationStream> {
+        let (tx, rx) = mpsc::unbounded();
+        self.shared.subscriptions.lock().insert(id, tx);
+        Ok(rx)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) -> error::Result<()> {
+        self.shared.subscriptions.lock().remove(&id);
+        Ok(())
+    }
+}
+
+/// Background task: pumps outgoing payloads and parses newline-delimited frames.
+async fn run<S>(mut stream: S, shared: Arc<Shared>, mut messages: mpsc::UnboundedReceiver<Vec<u8>>)
+where
+    S: AsyncReadExt + AsyncWriteExt + Unpin + Send + 'static,
+{
+    let mut buffer = Vec::new();
+    let mut read = [0u8; 4096];
+    loop {
+        futures::select! {
+            payload = messages.next().fuse() => match payload {
+                Some(payload) => {
+                    if stream.write_all(&payload).await.is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            },
+            n = stream.read(&mut read).fuse() => match n {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    buffer.extend_from_slice(&read[..n]);
+                    while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                        let frame: Vec<u8> = buffer.drain(..=pos).collect();
+                        dispatch(&shared, &frame[..frame.len() - 1]);
+                    }
+                }
+            },
+        }
+    }
+
+    // Fail everything still outstanding once the socket is gone.
+    for (_, pending) in shared.pending.lock().split_off(&0) {
+        let _ = pending.send(Err(error::Error::Transport("IPC connection closed".into())));
+    }
+}
+
+/// Parse a single frame as either a response or a subscription notification.
+fn dispatch(shared: &Shared, frame: &[u8]) {
+    if let Ok(response) = helpers::to_response_from_slice(frame) {
+        match response {
+            rpc::Response::Single(output) => shared.respond(output),
+            rpc::Response::Batch(outputs) => outputs.into_iter().for_each(|o| shared.respond(o)),
+        }
+        return;
+    }
+    if let Ok(notification) = helpers::to_notification_from_slice(frame) {
+        shared.notify(notification);
+    }
+}
@@ -0,0 +1,7 @@
+//! Supported Web3 transports.
+
+pub mod ipc;
+pub mod ws;
+
+pub use self::ipc::Ipc;
+pub use self::ws::{PingConfig, Ws};
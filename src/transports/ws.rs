@@ -0,0 +1,377 @@
+//! WebSocket transport with keep-alive supervision.
+//!
+//! The transport drives a single background task that pumps outgoing requests
+//! and fans incoming frames out to the pending `CallFuture`s and subscription
+//! streams. A silently dropped connection — for instance an idle TCP stream
+//! reaped by a load balancer — would otherwise leave that task blocked on a
+//! read that never returns, so every pending request hangs forever.
+//!
+//! To detect this the task arms an interval timer that, on each tick, sends a WS
+//! Ping frame and consults [`PingState`]: any inbound traffic (a Pong, a reply,
+//! a notification) refreshes an activity timestamp, and a run of ticks with no
+//! traffic within `inactive_limit` trips the connection. When it trips, the
+//! socket is closed and every pending request is failed with
+//! `Error::Transport("connection inactive")`.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::api::SubscriptionId;
+use crate::error;
+use crate::helpers;
+use crate::rpc;
+use crate::{DuplexTransport, RequestId, Transport};
+use futures::channel::{mpsc, oneshot};
+use futures::future::{self, BoxFuture};
+use futures::{FutureExt, SinkExt, StreamExt};
+use parking_lot::Mutex;
+use soketto::connection::{Incoming, Receiver, Sender};
+use soketto::handshake::{Client, ServerResponse};
+use soketto::Data;
+use tokio::net::TcpStream;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+use url::Url;
+
+/// Configuration for the WebSocket keep-alive supervisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PingConfig {
+    /// How often a Ping frame is sent.
+    pub ping_interval: Duration,
+    /// Number of consecutive inactive ticks tolerated before the connection is
+    /// declared dead.
+    pub max_failures: usize,
+    /// Window within which some inbound traffic must be observed; a tick with no
+    /// traffic inside this window counts as a failure.
+    pub inactive_limit: Duration,
+}
+
+impl Default for PingConfig {
+    fn default() -> Self {
+        PingConfig {
+            ping_interval: Duration::from_secs(30),
+            max_failures: 1,
+            inactive_limit: Duration::from_secs(40),
+        }
+    }
+}
+
+/// Liveness state maintained by the WS background task.
+///
+/// The task records inbound activity via [`PingState::refresh`] on every frame
+/// it reads and consults [`PingState::tick`] whenever the ping interval elapses.
+#[derive(Debug)]
+pub struct PingState {
+    config: PingConfig,
+    last_activity: Instant,
+    failures: usize,
+}
+
+impl PingState {
+    /// Start supervising a freshly established connection.
+    pub fn new(config: PingConfig) -> Self {
+        PingState {
+            config,
+            last_activity: Instant::now(),
+            failures: 0,
+        }
+    }
+
+    /// The configured ping interval, used by the task to arm its timer.
+    pub fn ping_interval(&self) -> Duration {
+        self.config.ping_interval
+    }
+
+    /// Record inbound traffic. Any frame — including a Pong — refreshes the
+    /// activity timestamp and clears the consecutive-failure counter.
+    pub fn refresh(&mut self) {
+        self.last_activity = Instant::now();
+        self.failures = 0;
+    }
+
+    /// Handle a ping tick. Increments the failure counter when no traffic has
+    /// been seen within `inactive_limit`; returns `true` once `max_failures`
+    /// consecutive inactive ticks have accumulated, signalling the task to close
+    /// the socket and fail all pending requests.
+    pub fn tick(&mut self) -> bool {
+        if self.last_activity.elapsed() >= self.config.inactive_limit {
+            self.failures += 1;
+        } else {
+            self.failures = 0;
+        }
+        self.failures >= self.config.max_failures
+    }
+}
+
+/// Sender for a single pending request.
+type Pending = oneshot::Sender<error::Result<rpc::Value>>;
+/// Sender for an active subscription stream.
+type Subscription = mpsc::UnboundedSender<rpc::Value>;
+
+/// Shared state routing replies back to callers and notifications to streams.
+#[derive(Default)]
+struct Shared {
+    pending: Mutex<BTreeMap<RequestId, Pending>>,
+    subscriptions: Mutex<BTreeMap<SubscriptionId, Subscription>>,
+}
+
+impl Shared {
+    /// Dispatch a single decoded output to its waiting caller.
+    fn respond(&self, output: rpc::Output) {
+        let id = match &output {
+            rpc::Output::Success(s) => &s.id,
+            rpc::Output::Failure(f) => &f.id,
+        };
+        if let rpc::Id::Num(num) = id {
+            if let Some(pending) = self.pending.lock().remove(&(*num as usize)) {
+                let _ = pending.send(helpers::to_result_from_output(output));
+            }
+        }
+    }
+
+    /// Route a subscription notification to the registered stream.
+    fn notify(&self, notification: rpc::Notification) {
+        if let rpc::Params::Map(params) = notification.params {
+            let id = params.get("subscription").and_then(rpc::Value::as_str).map(SubscriptionId::from);
+            let result = params.get("result").cloned();
+            if let (Some(id), Some(result)) = (id, result) {
+                let mut subs = self.subscriptions.lock();
+                if let Some(stream) = subs.get(&id) {
+                    if stream.unbounded_send(result).is_err() {
+                        subs.remove(&id);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fail every outstanding request with the given error.
+    fn fail_all(&self, make_error: impl Fn() -> error::Error) {
+        for (_, pending) in self.pending.lock().split_off(&0) {
+            let _ = pending.send(Err(make_error()));
+        }
+    }
+}
+
+/// WebSocket transport.
+#[derive(Clone)]
+pub struct Ws {
+    id: Arc<AtomicUsize>,
+    shared: Arc<Shared>,
+    messages: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Ws {
+    /// Connect to the node, driving the connection with the default
+    /// [`PingConfig`].
+    pub async fn new(url: &str) -> error::Result<Self> {
+        Ws::with_config(url, PingConfig::default()).await
+    }
+
+    /// Connect to the node, supervising the connection with `config`.
+    pub async fn with_config(url: &str, config: PingConfig) -> error::Result<Self> {
+        let (sender, receiver) = handshake(url).await?;
+        let shared = Arc::new(Shared::default());
+        let (messages, outgoing) = mpsc::unbounded();
+        tokio::spawn(run(sender, receiver, shared.clone(), outgoing, config));
+        Ok(Ws {
+            id: Arc::new(AtomicUsize::new(1)),
+            shared,
+            messages,
+        })
+    }
+}
+
+impl std::fmt::Debug for Ws {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Ws").finish()
+    }
+}
+
+/// Future produced by a WS call.
+type CallResult = BoxFuture<'static, error::Result<rpc::Value>>;
+
+impl Transport for Ws {
+    type Out = CallResult;
+
+    fn prepare(&self, method: &str, params: Vec<rpc::Value>) -> (RequestId, rpc::Call) {
+        let id = self.id.fetch_add(1, Ordering::AcqRel);
+        (id, helpers::build_request(id, method, params))
+    }
+
+    fn send(&self, id: RequestId, request: rpc::Call) -> Self::Out {
+        let (tx, rx) = oneshot::channel();
+        self.shared.pending.lock().insert(id, tx);
+
+        let payload = helpers::to_string(&rpc::Request::Single(request)).into_bytes();
+        if self.messages.unbounded_send(payload).is_err() {
+            self.shared.pending.lock().remove(&id);
+            return future::ready(Err(error::Error::Transport("WS task terminated".into()))).boxed();
+        }
+
+        async move { rx.await.unwrap_or_else(|_| Err(error::Error::Transport("WS task terminated".into()))) }.boxed()
+    }
+}
+
+impl DuplexTransport for Ws {
+    type NotificationStream = mpsc::UnboundedReceiver<rpc::Value>;
+
+    fn subscribe(&self, id: SubscriptionId) -> error::Result<Self::NotificationStream> {
+        let (tx, rx) = mpsc::unbounded();
+        self.shared.subscriptions.lock().insert(id, tx);
+        Ok(rx)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) -> error::Result<()> {
+        self.shared.subscriptions.lock().remove(&id);
+        Ok(())
+    }
+}
+
+/// Background task: pumps outgoing payloads, dispatches inbound frames, and runs
+/// the ping/inactivity supervisor.
+async fn run(
+    mut sender: Sender,
+    mut receiver: Receiver,
+    shared: Arc<Shared>,
+    mut messages: mpsc::UnboundedReceiver<Vec<u8>>,
+    config: PingConfig,
+) {
+    let mut state = PingState::new(config);
+    let mut ping_timer = tokio::time::interval(state.ping_interval());
+    let mut frame = Vec::new();
+    let mut inactive = false;
+
+    loop {
+        futures::select! {
+            _ = ping_timer.tick().fuse() => {
+                // Probe the connection and advance the inactivity counter.
+                let _ = sender.send_ping(Data::Binary(0)).await;
+                let _ = sender.flush().await;
+                if state.tick() {
+                    inactive = true;
+                    break;
+                }
+            }
+            payload = messages.next().fuse() => match payload {
+                Some(payload) => {
+                    if sender.send_text(String::from_utf8_lossy(&payload)).await.is_err()
+                        || sender.flush().await.is_err()
+                    {
+                        break;
+                    }
+                }
+                None => break,
+            },
+            incoming = receiver.receive(&mut frame).fuse() => match incoming {
+                // Any inbound frame is proof of life, including the Pong that
+                // our own ping elicits — soketto surfaces those through
+                // `receive` (but not through `receive_data`), so refresh first
+                // and only forward data frames to the dispatcher.
+                Ok(Incoming::Data(_)) => {
+                    state.refresh();
+                    dispatch(&shared, &frame);
+                    frame.clear();
+                }
+                Ok(Incoming::Pong(_)) => {
+                    state.refresh();
+                    frame.clear();
+                }
+                Ok(Incoming::Closed(_)) | Err(_) => break,
+            },
+        }
+    }
+
+    // Close the socket and fail everything still outstanding. The inactivity
+    // path reports the dedicated error; a clean shutdown reports termination.
+    let _ = sender.close().await;
+    if inactive {
+        shared.fail_all(|| error::Error::Transport("connection inactive".into()));
+    } else {
+        shared.fail_all(|| error::Error::Transport("WS task terminated".into()));
+    }
+}
+
+/// Parse a single frame as either a response or a subscription notification.
+fn dispatch(shared: &Shared, frame: &[u8]) {
+    if let Ok(response) = helpers::to_response_from_slice(frame) {
+        match response {
+            rpc::Response::Single(output) => shared.respond(output),
+            rpc::Response::Batch(outputs) => outputs.into_iter().for_each(|o| shared.respond(o)),
+        }
+        return;
+    }
+    if let Ok(notification) = helpers::to_notification_from_slice(frame) {
+        shared.notify(notification);
+    }
+}
+
+/// Establish the WebSocket connection and split it into a sender/receiver pair.
+async fn handshake(url: &str) -> error::Result<(Sender, Receiver)> {
+    let url = Url::parse(url).map_err(|e| error::Error::Transport(format!("invalid WS url: {}", e)))?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| error::Error::Transport("WS url is missing a host".into()))?;
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| error::Error::Transport("WS url is missing a port".into()))?;
+    let resource = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_owned(),
+    };
+
+    let socket = TcpStream::connect((host, port)).await?;
+    let mut client = Client::new(socket.compat(), host, &resource);
+    match client.handshake().await.map_err(|e| error::Error::Transport(format!("{:?}", e)))? {
+        ServerResponse::Accepted { .. } => Ok(client.into_builder().finish()),
+        ServerResponse::Redirect { location, .. } => {
+            Err(error::Error::Transport(format!("WS server redirected to {}", location)))
+        }
+        ServerResponse::Rejected { status_code } => {
+            Err(error::Error::Transport(format!("WS handshake rejected: {}", status_code)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PingConfig, PingState};
+    use std::time::Duration;
+
+    fn config(max_failures: usize) -> PingConfig {
+        PingConfig {
+            ping_interval: Duration::from_millis(1),
+            max_failures,
+            // Zero window means every tick without a preceding refresh counts
+            // as inactive, which keeps the counting logic deterministic.
+            inactive_limit: Duration::from_secs(0),
+        }
+    }
+
+    #[test]
+    fn trips_after_max_consecutive_failures() {
+        let mut state = PingState::new(config(3));
+        assert!(!state.tick());
+        assert!(!state.tick());
+        assert!(state.tick());
+    }
+
+    #[test]
+    fn activity_resets_the_failure_counter() {
+        let mut state = PingState::new(config(2));
+        assert!(!state.tick());
+        state.refresh();
+        // Counter was cleared, so a single further inactive tick must not trip.
+        assert!(!state.tick());
+        assert!(state.tick());
+    }
+
+    #[test]
+    fn defaults_match_the_documented_values() {
+        let config = PingConfig::default();
+        assert_eq!(config.ping_interval, Duration::from_secs(30));
+        assert_eq!(config.inactive_limit, Duration::from_secs(40));
+        assert_eq!(config.max_failures, 1);
+    }
+}